@@ -16,7 +16,8 @@
 //! // prints: [1, 2, 3, 0, 5, 6, 7, 8, 0, 10, 11, 12, 13, 0, 15]
 //! ```
 use core::{
-    iter::{Skip, StepBy, Take},
+    cell::Cell,
+    iter::{Rev, Skip, StepBy, Take},
     ops::{Index, IndexMut, Range, RangeBounds},
 };
 
@@ -33,15 +34,33 @@ where
     fn grid(self, columns: usize) -> Grid<I> {
         Grid {
             columns,
+            pivot: Pivot::TopLeft,
+            rows: Cell::new(None),
             inner: self,
         }
     }
 }
 
+/// Corner of the grid that `(col, row)` coordinates passed to [`Grid::get`]/[`Grid::get_mut`]
+/// are taken relative to. The flat buffer underneath is always stored top-left, row-major;
+/// any other pivot is translated into that layout before indexing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Pivot {
+    #[default]
+    TopLeft,
+    BottomLeft,
+    TopRight,
+    BottomRight,
+}
+
 ///The Grid struct wraps an Iterator and provies two dimensional access over its contents.
 #[derive(Debug, Clone)]
 pub struct Grid<I> {
     pub columns: usize,
+    pub pivot: Pivot,
+    /// Cached row count, lazily filled in by [`Grid::rows`] (and [`Grid::with_pivot`]
+    /// for the pivots that need it) so repeated lookups don't re-walk the grid.
+    rows: Cell<Option<usize>>,
     inner: I,
 }
 
@@ -58,6 +77,91 @@ where
     }
 }
 
+/// Iterator over a single column of a [`Grid`], returned by [`Grid::iter_col`].
+#[derive(Debug, Clone)]
+pub struct ColIter<J> {
+    inner: StepBy<Skip<J>>,
+}
+
+impl<J: Iterator> Iterator for ColIter<J> {
+    type Item = J::Item;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<J: ExactSizeIterator> ExactSizeIterator for ColIter<J> {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl<J: DoubleEndedIterator + ExactSizeIterator> DoubleEndedIterator for ColIter<J> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+/// Iterator over a single row of a [`Grid`], returned by [`Grid::iter_row`].
+#[derive(Debug, Clone)]
+pub struct RowIter<J> {
+    inner: Take<Skip<J>>,
+}
+
+impl<J: Iterator> Iterator for RowIter<J> {
+    type Item = J::Item;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<J: ExactSizeIterator> ExactSizeIterator for RowIter<J> {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl<J: DoubleEndedIterator + ExactSizeIterator> DoubleEndedIterator for RowIter<J> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+/// Iterator over a single diagonal of a [`Grid`], returned by [`Grid::iter_diag_fwd`]
+/// and [`Grid::iter_diag_bwd`].
+#[derive(Debug, Clone)]
+pub struct DiagIter<J> {
+    inner: Take<StepBy<Skip<J>>>,
+}
+
+impl<J: Iterator> Iterator for DiagIter<J> {
+    type Item = J::Item;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<J: ExactSizeIterator> ExactSizeIterator for DiagIter<J> {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl<J: DoubleEndedIterator + ExactSizeIterator> DoubleEndedIterator for DiagIter<J> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
 impl<I> Grid<I> {
     pub fn index_from_flat(&self, index: usize) -> (usize, usize) {
         assert!(self.columns != 0, "Columns set to 0! Cant calculate index");
@@ -67,6 +171,41 @@ impl<I> Grid<I> {
     pub fn index_to_flat(&self, col: usize, row: usize) -> usize {
         self.columns * row + col
     }
+
+    /// Row count cached by [`Grid::rows`]/[`Grid::with_pivot`]. Panics if nothing
+    /// has populated the cache yet.
+    fn cached_rows(&self) -> usize {
+        self.rows
+            .get()
+            .expect("row count not cached; call `rows()` or `with_pivot()` first")
+    }
+}
+
+impl<I> Grid<I>
+where
+    I: IntoIterator + Clone,
+{
+    /// Number of rows, derived from the element count and `columns`. Cached
+    /// after the first call so repeated lookups don't re-walk the grid.
+    pub fn rows(&self) -> usize {
+        if let Some(rows) = self.rows.get() {
+            return rows;
+        }
+        assert!(self.columns != 0, "Columns set to 0! Cant calculate index");
+        let rows = self.inner.clone().into_iter().count() / self.columns;
+        self.rows.set(Some(rows));
+        rows
+    }
+
+    /// Sets the corner that `(col, row)` coordinates passed to `get`/`get_mut` are
+    /// taken relative to. Defaults to [`Pivot::TopLeft`].
+    pub fn with_pivot(mut self, pivot: Pivot) -> Self {
+        self.pivot = pivot;
+        if matches!(pivot, Pivot::BottomLeft | Pivot::BottomRight) {
+            self.rows.set(Some(self.rows()));
+        }
+        self
+    }
 }
 
 impl<'a, I> Grid<I>
@@ -85,11 +224,126 @@ where
         assert!(columns % len == 0);
         Grid {
             columns: len / columns,
+            pivot: Pivot::TopLeft,
+            rows: Cell::new(None),
             inner: (0..columns)
                 .flat_map(|col| iter.clone().grid(columns).iter_col(col))
                 .collect(),
         }
     }
+
+    ///```rust
+    ///
+    /// // 1 2 3        1 2 3
+    /// // 4 5 6  =>    7 8 9
+    /// //              4 5 6
+    ///
+    /// use iter_grid::IntoGrid;
+    /// let grid = (1..7).collect::<Vec<_>>().grid(3).insert_row_at(1, vec![7, 8, 9]);
+    /// assert!(grid.columns == 3);
+    /// assert!(grid.into_iter().eq([1, 2, 3, 7, 8, 9, 4, 5, 6]));
+    ///```
+    pub fn insert_row_at(self, row: usize, items: impl IntoIterator<Item = I::Item>) -> Grid<I> {
+        let columns = self.columns;
+        let split = row * columns;
+        let iter = self.inner.into_iter();
+        let before = iter.clone().take(split);
+        let after = iter.skip(split);
+        Grid {
+            columns,
+            pivot: Pivot::TopLeft,
+            rows: Cell::new(None),
+            inner: before.chain(items).chain(after).collect(),
+        }
+    }
+
+    ///```rust
+    ///
+    /// // 1 2 3        1 7 2 3
+    /// // 4 5 6  =>    4 8 5 6
+    ///
+    /// use iter_grid::IntoGrid;
+    /// let grid = (1..7).collect::<Vec<_>>().grid(3).insert_col_at(1, vec![7, 8]);
+    /// assert!(grid.columns == 4);
+    /// assert!(grid.into_iter().eq([1, 7, 2, 3, 4, 8, 5, 6]));
+    ///```
+    pub fn insert_col_at(self, col: usize, items: impl IntoIterator<Item = I::Item>) -> Grid<I> {
+        let columns = self.columns;
+        let iter = self.inner.into_iter();
+        let rows = iter.clone().count() / columns;
+        let mut items = items.into_iter();
+        Grid {
+            columns: columns + 1,
+            pivot: Pivot::TopLeft,
+            rows: Cell::new(None),
+            inner: (0..rows)
+                .flat_map(move |row| {
+                    let start = row * columns;
+                    let before = iter.clone().skip(start).take(col);
+                    let after = iter.clone().skip(start + col).take(columns - col);
+                    before
+                        .chain(core::iter::once(items.next().expect(
+                            "not enough items to insert one per row",
+                        )))
+                        .chain(after)
+                })
+                .collect(),
+        }
+    }
+
+    ///```rust
+    ///
+    /// // 1 2 3
+    /// // 4 5 6  =>  1 2 3
+    /// // 7 8 9      7 8 9
+    ///
+    /// use iter_grid::IntoGrid;
+    /// let grid = (1..10).collect::<Vec<_>>().grid(3).remove_row(1);
+    /// assert!(grid.columns == 3);
+    /// assert!(grid.into_iter().eq([1, 2, 3, 7, 8, 9]));
+    ///```
+    pub fn remove_row(self, row: usize) -> Grid<I> {
+        let columns = self.columns;
+        let start = row * columns;
+        let iter = self.inner.into_iter();
+        let before = iter.clone().take(start);
+        let after = iter.skip(start + columns);
+        Grid {
+            columns,
+            pivot: Pivot::TopLeft,
+            rows: Cell::new(None),
+            inner: before.chain(after).collect(),
+        }
+    }
+
+    ///```rust
+    ///
+    /// // 1 2 3        1 3
+    /// // 4 5 6  =>    4 6
+    ///
+    /// use iter_grid::IntoGrid;
+    /// let grid = (1..7).collect::<Vec<_>>().grid(3).remove_col(1);
+    /// assert!(grid.columns == 2);
+    /// assert!(grid.into_iter().eq([1, 3, 4, 6]));
+    ///```
+    pub fn remove_col(self, col: usize) -> Grid<I> {
+        let columns = self.columns;
+        let iter = self.inner.into_iter();
+        let rows = iter.clone().count() / columns;
+        Grid {
+            columns: columns - 1,
+            pivot: Pivot::TopLeft,
+            rows: Cell::new(None),
+            inner: (0..rows)
+                .flat_map(move |row| {
+                    let start = row * columns;
+                    let before = iter.clone().skip(start).take(col);
+                    let after = iter.clone().skip(start + col + 1).take(columns - col - 1);
+                    before.chain(after)
+                })
+                .collect(),
+        }
+    }
 }
 impl<'a, I> Grid<I>
 where
@@ -104,6 +358,159 @@ where
         let iter = self.inner.into_iter();
         (0..self.columns).flat_map(move |col| iter.clone().grid(self.columns).iter_col(col))
     }
+
+    /// 1 2 3    3 6
+    /// 4 5 6 => 2 5
+    ///          1 4
+    ///
+    pub fn iter_rotate_ccw(self) -> Grid<impl IntoIterator<Item = I::Item> + 'a> {
+        let columns = self.columns;
+        let iter = self.inner.into_iter();
+        let rows = iter.clone().count() / columns;
+        Grid {
+            columns: rows,
+            pivot: Pivot::TopLeft,
+            rows: Cell::new(None),
+            inner: (0..columns)
+                .rev()
+                .flat_map(move |col| iter.clone().grid(columns).iter_col(col)),
+        }
+    }
+
+    /// 1 2 3    4 5 6
+    /// 4 5 6 => 1 2 3
+    ///
+    pub fn iter_flip_vertical(self) -> Grid<impl IntoIterator<Item = I::Item> + 'a> {
+        let columns = self.columns;
+        let iter = self.inner.into_iter();
+        let rows = iter.clone().count() / columns;
+        Grid {
+            columns,
+            pivot: Pivot::TopLeft,
+            rows: Cell::new(None),
+            inner: (0..rows)
+                .rev()
+                .flat_map(move |row| iter.clone().grid(columns).iter_row(row)),
+        }
+    }
+
+    ///```rust
+    ///
+    /// // every 2x2 block of
+    /// // 1 2 3
+    /// // 4 5 6
+    /// // 7 8 9
+    ///
+    /// use iter_grid::IntoGrid;
+    /// (1..10).grid(3)
+    ///     .iter_windows(2, 2)
+    ///     .map(|w| w.into_iter().collect::<Vec<_>>())
+    ///     .zip([
+    ///         vec![1, 2, 4, 5],
+    ///         vec![2, 3, 5, 6],
+    ///         vec![4, 5, 7, 8],
+    ///         vec![5, 6, 8, 9],
+    ///     ])
+    ///     .for_each(|(l, r)| assert!(l == r));
+    ///```
+    pub fn iter_windows(
+        self,
+        win_cols: usize,
+        win_rows: usize,
+    ) -> impl Iterator<Item = Grid<impl IntoIterator<Item = I::Item> + 'a>> + 'a {
+        self.iter_windows_strided(win_cols, win_rows, 1)
+    }
+
+    ///```rust
+    ///
+    /// // every 2x2 block of, skipping a column/row between windows
+    /// // 1 2 3 4
+    /// // 5 6 7 8
+    /// // 9 10 11 12
+    ///
+    /// use iter_grid::IntoGrid;
+    /// (1..13).grid(4)
+    ///     .iter_windows_strided(2, 2, 2)
+    ///     .map(|w| w.into_iter().collect::<Vec<_>>())
+    ///     .zip([vec![1, 2, 5, 6], vec![3, 4, 7, 8]])
+    ///     .for_each(|(l, r)| assert!(l == r));
+    ///```
+    pub fn iter_windows_strided(
+        self,
+        win_cols: usize,
+        win_rows: usize,
+        stride: usize,
+    ) -> impl Iterator<Item = Grid<impl IntoIterator<Item = I::Item> + 'a>> + 'a {
+        let columns = self.columns;
+        let base_iter = self.inner.into_iter();
+        let rows = base_iter.clone().count() / columns;
+        // `checked_sub` rather than `saturating_sub`: a window bigger than the
+        // grid must yield zero windows, not panic downstream in `iter_sub`.
+        let row_windows = rows.checked_sub(win_rows).map_or(0, |span| span + 1);
+        let col_windows = columns.checked_sub(win_cols).map_or(0, |span| span + 1);
+        (0..row_windows).step_by(stride).flat_map(move |row| {
+            let base_iter = base_iter.clone();
+            (0..col_windows).step_by(stride).map(move |col| {
+                base_iter
+                    .clone()
+                    .grid(columns)
+                    .iter_sub(col..col + win_cols, row..row + win_rows)
+            })
+        })
+    }
+}
+impl<'a, I> Grid<I>
+where
+    I: IntoIterator,
+    I::IntoIter: Clone + DoubleEndedIterator + ExactSizeIterator + 'a,
+{
+    /// 1 2 3    4 1
+    /// 4 5 6 => 5 2
+    ///          6 3
+    ///
+    pub fn iter_rotate_cw(self) -> Grid<impl IntoIterator<Item = I::Item> + 'a> {
+        let columns = self.columns;
+        let iter = self.inner.into_iter();
+        let rows = iter.clone().count() / columns;
+        Grid {
+            columns: rows,
+            pivot: Pivot::TopLeft,
+            rows: Cell::new(None),
+            inner: (0..columns).flat_map(move |col| iter.clone().grid(columns).iter_col(col).rev()),
+        }
+    }
+
+    /// 1 2 3    3 2 1
+    /// 4 5 6 => 6 5 4
+    ///
+    pub fn iter_flip_horizontal(self) -> Grid<impl IntoIterator<Item = I::Item> + 'a> {
+        let columns = self.columns;
+        let iter = self.inner.into_iter();
+        let rows = iter.clone().count() / columns;
+        Grid {
+            columns,
+            pivot: Pivot::TopLeft,
+            rows: Cell::new(None),
+            inner: (0..rows).flat_map(move |row| iter.clone().grid(columns).iter_row(row).rev()),
+        }
+    }
+}
+impl<I> Grid<I>
+where
+    I: IntoIterator,
+    I::IntoIter: DoubleEndedIterator,
+{
+    /// 1 2 3    6 5 4
+    /// 4 5 6 => 3 2 1
+    ///
+    pub fn iter_rotate_180(self) -> Grid<Rev<I::IntoIter>> {
+        Grid {
+            columns: self.columns,
+            pivot: Pivot::TopLeft,
+            rows: Cell::new(None),
+            inner: self.inner.into_iter().rev(),
+        }
+    }
 }
 impl<I> Grid<I>
 where
@@ -121,10 +528,31 @@ where
         let col_range = self.extract_range(&col_bounds, columns);
         Grid {
             columns: col_range.end - col_range.start,
+            pivot: Pivot::TopLeft,
+            rows: Cell::new(None),
             inner: self.iter_rows(row_bounds).iter_cols(col_bounds),
         }
     }
 
+    ///```rust
+    ///
+    /// // yields ((col,row), item) for every cell, row-major
+    ///
+    /// use iter_grid::IntoGrid;
+    /// (0..6).grid(3)
+    ///     .indexed()
+    ///     .zip([((0,0),0),((1,0),1),((2,0),2),((0,1),3),((1,1),4),((2,1),5)])
+    ///     .for_each(|(l, r)| assert!(l == r));
+    ///```
+    pub fn indexed(self) -> impl Iterator<Item = ((usize, usize), I::Item)> {
+        let columns = self.columns;
+        assert!(columns != 0, "Columns set to 0! Cant calculate index");
+        self.inner.into_iter().enumerate().map(move |(i, item)| {
+            let c = i % columns;
+            ((c, (i - c) / columns), item)
+        })
+    }
+
     ///```rust
     ///
     /// // . x .
@@ -137,9 +565,27 @@ where
     ///     .zip([3,8,13,18,23])
     ///     .for_each(|(l, r)| assert!(l == r));
     ///```   
-    pub fn iter_col(self, col: usize) -> StepBy<Skip<I::IntoIter>> {
+    pub fn iter_col(self, col: usize) -> ColIter<I::IntoIter> {
         assert!(col < self.columns);
-        self.inner.into_iter().skip(col).step_by(self.columns)
+        ColIter {
+            inner: self.inner.into_iter().skip(col).step_by(self.columns),
+        }
+    }
+
+    ///```rust
+    ///
+    /// // tags each item of the column with its (col,row) position
+    ///
+    /// use iter_grid::IntoGrid;
+    /// (0..25).grid(5)
+    ///     .indexed_col(3)
+    ///     .zip([((3,0),3),((3,1),8),((3,2),13),((3,3),18),((3,4),23)])
+    ///     .for_each(|(l, r)| assert!(l == r));
+    ///```
+    pub fn indexed_col(self, col: usize) -> impl Iterator<Item = ((usize, usize), I::Item)> {
+        self.iter_col(col)
+            .enumerate()
+            .map(move |(row, item)| ((col, row), item))
     }
 
     ///```rust
@@ -154,11 +600,30 @@ where
     ///     .zip(15..20)
     ///     .for_each(|(l, r)| assert!(l == r));
     ///```
-    pub fn iter_row(self, row: usize) -> Take<Skip<I::IntoIter>> {
-        self.inner
-            .into_iter()
-            .skip(row * self.columns)
-            .take(self.columns)
+    pub fn iter_row(self, row: usize) -> RowIter<I::IntoIter> {
+        RowIter {
+            inner: self
+                .inner
+                .into_iter()
+                .skip(row * self.columns)
+                .take(self.columns),
+        }
+    }
+
+    ///```rust
+    ///
+    /// // tags each item of the row with its (col,row) position
+    ///
+    /// use iter_grid::IntoGrid;
+    /// (0..25).grid(5)
+    ///     .indexed_row(3)
+    ///     .zip([((0,3),15),((1,3),16),((2,3),17),((3,3),18),((4,3),19)])
+    ///     .for_each(|(l, r)| assert!(l == r));
+    ///```
+    pub fn indexed_row(self, row: usize) -> impl Iterator<Item = ((usize, usize), I::Item)> {
+        self.iter_row(row)
+            .enumerate()
+            .map(move |(col, item)| ((col, row), item))
     }
 
     ///```rust
@@ -177,7 +642,7 @@ where
     ///     .zip([9,13,17,21])
     ///     .for_each(|(l, r)| assert!(l == r));
     ///```
-    pub fn iter_diag_bwd(self, col: usize, row: usize) -> StepBy<Skip<I::IntoIter>> {
+    pub fn iter_diag_bwd(self, col: usize, row: usize) -> DiagIter<I::IntoIter> {
         let skip = if col > row {
             // lower part
             self.index_to_flat(self.columns - 1, row - (self.columns - 1 - col))
@@ -185,7 +650,15 @@ where
             // upper part
             self.index_to_flat(row - col, 0)
         };
-        self.inner.into_iter().skip(skip).step_by(self.columns - 1)
+        DiagIter {
+            // stop at the left edge instead of wrapping onto the next diagonal
+            inner: self
+                .inner
+                .into_iter()
+                .skip(skip)
+                .step_by(self.columns - 1)
+                .take(skip % self.columns + 1),
+        }
     }
     ///```rust
     ///
@@ -203,14 +676,22 @@ where
     ///     .zip([2,8,14])
     ///     .for_each(|(l, r)| assert!(l == r));
     ///```
-    pub fn iter_diag_fwd(self, col: usize, row: usize) -> StepBy<Skip<I::IntoIter>> {
+    pub fn iter_diag_fwd(self, col: usize, row: usize) -> DiagIter<I::IntoIter> {
         let mut diff = col.abs_diff(row);
         if col < row {
             diff = self.index_to_flat(0, diff)
         } else {
             diff = self.index_to_flat(diff, 0);
         }
-        self.inner.into_iter().skip(diff).step_by(self.columns + 1)
+        DiagIter {
+            // stop at the right edge instead of wrapping onto the next diagonal
+            inner: self
+                .inner
+                .into_iter()
+                .skip(diff)
+                .step_by(self.columns + 1)
+                .take(self.columns - diff % self.columns),
+        }
     }
 
     ///```rust
@@ -285,12 +766,38 @@ where
     I: Index<usize>,
     I::Output: Sized,
 {
+    /// Translates a `(col, row)` given relative to `self.pivot` into the
+    /// top-left, row-major coordinates the flat buffer is stored in.
+    ///
+    /// Panics for `BottomLeft`/`BottomRight` if the row count hasn't been
+    /// cached yet (call [`Grid::rows`] or [`Grid::with_pivot`] first).
+    fn translate(&self, col: usize, row: usize) -> (usize, usize) {
+        match self.pivot {
+            Pivot::TopLeft => (col, row),
+            Pivot::BottomLeft => (col, self.cached_rows() - 1 - row),
+            Pivot::TopRight => (self.columns - 1 - col, row),
+            Pivot::BottomRight => (self.columns - 1 - col, self.cached_rows() - 1 - row),
+        }
+    }
+
+    ///```rust
+    ///
+    /// // 1 2 3
+    /// // 4 5 6
+    ///
+    /// use iter_grid::{IntoGrid, Pivot};
+    /// let grid = (1..7).collect::<Vec<_>>().grid(3).with_pivot(Pivot::BottomLeft);
+    /// assert!(*grid.get(0, 0) == 4);
+    /// assert!(*grid.get(0, 1) == 1);
+    ///```
     pub fn get(&self, col: usize, row: usize) -> &I::Output {
         assert!(col < self.columns);
+        let (col, row) = self.translate(col, row);
         let index = self.index_to_flat(col, row);
         &self.inner[index]
     }
 }
+
 impl<I> Grid<I>
 where
     I: IndexMut<usize>,
@@ -298,10 +805,97 @@ where
 {
     pub fn get_mut(&mut self, col: usize, row: usize) -> &mut I::Output {
         assert!(col < self.columns);
+        let (col, row) = self.translate(col, row);
         let index = self.index_to_flat(col, row);
         &mut self.inner[index]
     }
 }
+
+impl<I> Grid<I>
+where
+    I: Index<usize> + IntoIterator + Clone,
+    I::Output: Sized,
+{
+    ///```rust
+    ///
+    /// // . x .
+    /// // x o x
+    /// // . x .
+    ///
+    /// use iter_grid::IntoGrid;
+    /// let grid = (0..9).collect::<Vec<_>>().grid(3);
+    /// grid.neighbors_von_neumann(1, 1, false)
+    ///     .zip([1, 3, 5, 7])
+    ///     .for_each(|(l, r)| assert!(*l == r));
+    /// // corners wrap around the edges when `wrap` is set
+    /// grid.neighbors_von_neumann(0, 0, true)
+    ///     .zip([6, 2, 1, 3])
+    ///     .for_each(|(l, r)| assert!(*l == r));
+    ///```
+    pub fn neighbors_von_neumann(
+        &self,
+        col: usize,
+        row: usize,
+        wrap: bool,
+    ) -> impl Iterator<Item = &I::Output> + '_ {
+        const OFFSETS: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+        self.neighbors(col, row, wrap, &OFFSETS)
+    }
+
+    ///```rust
+    ///
+    /// // x x x
+    /// // x o x
+    /// // x x x
+    ///
+    /// use iter_grid::IntoGrid;
+    /// let grid = (0..9).collect::<Vec<_>>().grid(3);
+    /// grid.neighbors_moore(1, 1, false)
+    ///     .zip([0, 1, 2, 3, 5, 6, 7, 8])
+    ///     .for_each(|(l, r)| assert!(*l == r));
+    ///```
+    pub fn neighbors_moore(
+        &self,
+        col: usize,
+        row: usize,
+        wrap: bool,
+    ) -> impl Iterator<Item = &I::Output> + '_ {
+        const OFFSETS: [(isize, isize); 8] = [
+            (-1, -1),
+            (0, -1),
+            (1, -1),
+            (-1, 0),
+            (1, 0),
+            (-1, 1),
+            (0, 1),
+            (1, 1),
+        ];
+        self.neighbors(col, row, wrap, &OFFSETS)
+    }
+
+    fn neighbors(
+        &self,
+        col: usize,
+        row: usize,
+        wrap: bool,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = &I::Output> + '_ {
+        let columns = self.columns as isize;
+        let rows = self.rows() as isize;
+        offsets.iter().filter_map(move |&(dc, dr)| {
+            let nc = col as isize + dc;
+            let nr = row as isize + dr;
+            let (nc, nr) = if wrap {
+                (nc.rem_euclid(columns), nr.rem_euclid(rows))
+            } else if nc < 0 || nc >= columns || nr < 0 || nr >= rows {
+                return None;
+            } else {
+                (nc, nr)
+            };
+            Some(self.get(nc as usize, nr as usize))
+        })
+    }
+}
 #[cfg(test)]
 mod tests {
     extern crate alloc;
@@ -335,4 +929,139 @@ mod tests {
         // println!("{store:?}")
         // prints: [1, 2, 3, 0, 5, 6, 7, 8, 0, 10, 11, 12, 13, 0, 15]
     }
+
+    #[test]
+    fn test_reverse_and_exact_size() {
+        let grid = (0..25).grid(5);
+        assert!(grid.clone().iter_col(3).len() == 5);
+        assert!(grid.clone().iter_col(3).next_back() == Some(23));
+        assert!(grid.clone().iter_col(3).rev().eq([23, 18, 13, 8, 3]));
+
+        assert!(grid.clone().iter_row(2).len() == 5);
+        assert!(grid.clone().iter_row(2).rev().eq([14, 13, 12, 11, 10]));
+
+        assert!(grid.iter_diag_fwd(1, 2).rev().eq([23, 17, 11, 5]));
+    }
+
+    #[test]
+    fn test_indexed() {
+        (0..6)
+            .grid(3)
+            .indexed()
+            .zip([((0, 0), 0), ((1, 0), 1), ((2, 0), 2), ((0, 1), 3), ((1, 1), 4), ((2, 1), 5)])
+            .for_each(|(l, r)| assert!(l == r));
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = (0..9).collect::<Vec<_>>().grid(3);
+        assert!(grid.rows() == 3);
+
+        let von_neumann: Vec<_> = grid.neighbors_von_neumann(1, 1, false).copied().collect();
+        assert!(von_neumann == [1, 3, 5, 7]);
+
+        let wrapped: Vec<_> = grid.neighbors_von_neumann(0, 0, true).copied().collect();
+        assert!(wrapped == [6, 2, 1, 3]);
+
+        let moore: Vec<_> = grid.neighbors_moore(1, 1, false).copied().collect();
+        assert!(moore == [0, 1, 2, 3, 5, 6, 7, 8]);
+    }
+
+    #[test]
+    fn test_rotate_and_flip() {
+        // 1 2 3
+        // 4 5 6
+        let grid = (1..7).collect::<Vec<_>>().grid(3);
+
+        let cw = grid.clone().iter_rotate_cw();
+        assert!(cw.columns == 2);
+        assert!(cw.into_iter().eq([4, 1, 5, 2, 6, 3]));
+
+        let ccw = grid.clone().iter_rotate_ccw();
+        assert!(ccw.columns == 2);
+        assert!(ccw.into_iter().eq([3, 6, 2, 5, 1, 4]));
+
+        let rot180 = grid.clone().iter_rotate_180();
+        assert!(rot180.columns == 3);
+        assert!(rot180.into_iter().eq([6, 5, 4, 3, 2, 1]));
+
+        let flip_h = grid.clone().iter_flip_horizontal();
+        assert!(flip_h.columns == 3);
+        assert!(flip_h.into_iter().eq([3, 2, 1, 6, 5, 4]));
+
+        let flip_v = grid.iter_flip_vertical();
+        assert!(flip_v.columns == 3);
+        assert!(flip_v.into_iter().eq([4, 5, 6, 1, 2, 3]));
+    }
+
+    #[test]
+    fn test_pivot() {
+        // 1 2 3
+        // 4 5 6
+        let grid = (1..7).collect::<Vec<_>>().grid(3);
+
+        let bottom_left = grid.clone().with_pivot(Pivot::BottomLeft);
+        assert!(*bottom_left.get(0, 0) == 4);
+        assert!(*bottom_left.get(0, 1) == 1);
+
+        let top_right = grid.clone().with_pivot(Pivot::TopRight);
+        assert!(*top_right.get(0, 0) == 3);
+        assert!(*top_right.get(2, 0) == 1);
+
+        let bottom_right = grid.with_pivot(Pivot::BottomRight);
+        assert!(*bottom_right.get(0, 0) == 6);
+        assert!(*bottom_right.get(2, 1) == 1);
+    }
+
+    #[test]
+    fn test_windows() {
+        // 1 2 3
+        // 4 5 6
+        // 7 8 9
+        let windows: Vec<Vec<i32>> = (1..10)
+            .grid(3)
+            .iter_windows(2, 2)
+            .map(|w| w.into_iter().collect())
+            .collect();
+        assert!(
+            windows
+                == [
+                    vec![1, 2, 4, 5],
+                    vec![2, 3, 5, 6],
+                    vec![4, 5, 7, 8],
+                    vec![5, 6, 8, 9],
+                ]
+        );
+
+        // a window bigger than the grid yields no windows instead of panicking
+        let none: Vec<Vec<i32>> = (1..10)
+            .grid(3)
+            .iter_windows(5, 2)
+            .map(|w| w.into_iter().collect())
+            .collect();
+        assert!(none.is_empty());
+    }
+
+    #[test]
+    fn test_insert_remove() {
+        // 1 2 3
+        // 4 5 6
+        let grid = (1..7).collect::<Vec<_>>().grid(3);
+
+        let with_row = grid.clone().insert_row_at(1, vec![7, 8, 9]);
+        assert!(with_row.columns == 3);
+        assert!(with_row.into_iter().eq([1, 2, 3, 7, 8, 9, 4, 5, 6]));
+
+        let with_col = grid.clone().insert_col_at(1, vec![7, 8]);
+        assert!(with_col.columns == 4);
+        assert!(with_col.into_iter().eq([1, 7, 2, 3, 4, 8, 5, 6]));
+
+        let no_row = grid.clone().remove_row(0);
+        assert!(no_row.columns == 3);
+        assert!(no_row.into_iter().eq([4, 5, 6]));
+
+        let no_col = grid.remove_col(1);
+        assert!(no_col.columns == 2);
+        assert!(no_col.into_iter().eq([1, 3, 4, 6]));
+    }
 }